@@ -116,4 +116,350 @@ impl Curve {
     pub fn intersect<T>(&self, other: &T) -> Option<Point2D<f32>> where T: Intersect {
         <Curve as Intersect>::intersect(self, other)
     }
+
+    /// Raises this quadratic curve to an equivalent cubic curve.
+    ///
+    /// This is lossless: the returned cubic curve traces exactly the same path as `self`.
+    #[inline]
+    pub fn elevate_to_cubic(&self) -> CubicCurve {
+        let (p0, p1, p2) = (&self.endpoints[0], &self.control_point, &self.endpoints[1]);
+        let control_point_0 = Point2D::new(p0.x + (p1.x - p0.x) * (2.0 / 3.0),
+                                            p0.y + (p1.y - p0.y) * (2.0 / 3.0));
+        let control_point_1 = Point2D::new(p2.x + (p1.x - p2.x) * (2.0 / 3.0),
+                                            p2.y + (p1.y - p2.y) * (2.0 / 3.0));
+        CubicCurve::new(p0, &control_point_0, &control_point_1, p2)
+    }
+}
+
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct CubicCurve {
+    pub endpoints: [Point2D<f32>; 2],
+    pub control_points: [Point2D<f32>; 2],
+}
+
+impl CubicCurve {
+    #[inline]
+    pub fn new(endpoint_0: &Point2D<f32>,
+               control_point_0: &Point2D<f32>,
+               control_point_1: &Point2D<f32>,
+               endpoint_1: &Point2D<f32>)
+               -> CubicCurve {
+        CubicCurve {
+            endpoints: [*endpoint_0, *endpoint_1],
+            control_points: [*control_point_0, *control_point_1],
+        }
+    }
+
+    #[inline]
+    pub fn sample(&self, t: f32) -> Point2D<f32> {
+        let (p0, p1, p2, p3) = (&self.endpoints[0],
+                                 &self.control_points[0],
+                                 &self.control_points[1],
+                                 &self.endpoints[1]);
+        let (ap1, bp1, cp1) = (p0.lerp(*p1, t), p1.lerp(*p2, t), p2.lerp(*p3, t));
+        let (ap2, bp2) = (ap1.lerp(bp1, t), bp1.lerp(cp1, t));
+        ap2.lerp(bp2, t)
+    }
+
+    #[inline]
+    pub fn subdivide(&self, t: f32) -> (CubicCurve, CubicCurve) {
+        let (p0, p1, p2, p3) = (&self.endpoints[0],
+                                 &self.control_points[0],
+                                 &self.control_points[1],
+                                 &self.endpoints[1]);
+        let (ap1, bp1, cp1) = (p0.lerp(*p1, t), p1.lerp(*p2, t), p2.lerp(*p3, t));
+        let (ap2, bp2) = (ap1.lerp(bp1, t), bp1.lerp(cp1, t));
+        let ap3bp0 = ap2.lerp(bp2, t);
+        (CubicCurve::new(p0, &ap1, &ap2, &ap3bp0), CubicCurve::new(&ap3bp0, &bp2, &cp1, p3))
+    }
+
+    #[inline]
+    pub fn to_path_segment(&self) -> PathCommand {
+        PathCommand::CubicCurveTo(self.control_points[0], self.control_points[1], self.endpoints[1])
+    }
+
+    /// Returns every `t` at which the curve's tangent is horizontal (y-axis roots) or vertical
+    /// (x-axis roots). A cubic's derivative is a quadratic, so each axis may have zero, one, or
+    /// two such roots.
+    pub fn inflection_points(&self) -> (Vec<f32>, Vec<f32>) {
+        let inflection_points_x = CubicCurve::inflection_point_x(self.endpoints[0].x,
+                                                                  self.control_points[0].x,
+                                                                  self.control_points[1].x,
+                                                                  self.endpoints[1].x);
+        let inflection_points_y = CubicCurve::inflection_point_x(self.endpoints[0].y,
+                                                                  self.control_points[0].y,
+                                                                  self.control_points[1].y,
+                                                                  self.endpoints[1].y);
+        (inflection_points_x, inflection_points_y)
+    }
+
+    #[inline]
+    pub fn baseline(&self) -> Line {
+        Line::new(&self.endpoints[0], &self.endpoints[1])
+    }
+
+    /// Finds every root of the derivative of one axis of the curve, i.e. every `t` at which that
+    /// axis' tangent is zero, in ascending order.
+    ///
+    /// The derivative of a cubic is a quadratic, so there may be up to two such roots.
+    fn inflection_point_x(endpoint_x_0: f32,
+                           control_point_x_0: f32,
+                           control_point_x_1: f32,
+                           endpoint_x_1: f32)
+                           -> Vec<f32> {
+        let a = 3.0 * (-endpoint_x_0 + 3.0 * control_point_x_0 - 3.0 * control_point_x_1 +
+                       endpoint_x_1);
+        let b = 6.0 * (endpoint_x_0 - 2.0 * control_point_x_0 + control_point_x_1);
+        let c = 3.0 * (control_point_x_0 - endpoint_x_0);
+
+        let mut roots = Vec::new();
+
+        if a.approx_eq(&0.0) {
+            if !b.approx_eq(&0.0) {
+                let t = -c / b;
+                if t > f32::approx_epsilon() && t < 1.0 - f32::approx_epsilon() {
+                    roots.push(t);
+                }
+            }
+            return roots
+        }
+
+        let discriminant = b * b - 4.0 * a * c;
+        if discriminant < 0.0 {
+            return roots
+        }
+        if discriminant.approx_eq(&0.0) {
+            let t = -b / (2.0 * a);
+            if t > f32::approx_epsilon() && t < 1.0 - f32::approx_epsilon() {
+                roots.push(t);
+            }
+            return roots
+        }
+        let sqrt_discriminant = discriminant.sqrt();
+        for t in [(-b - sqrt_discriminant) / (2.0 * a), (-b + sqrt_discriminant) / (2.0 * a)].iter() {
+            if *t > f32::approx_epsilon() && *t < 1.0 - f32::approx_epsilon() {
+                roots.push(*t);
+            }
+        }
+        roots.sort_by(|a, b| a.partial_cmp(b).unwrap());
+        roots
+    }
+
+    /// Solves for the `t` at which the curve's x coordinate equals `x`.
+    ///
+    /// Unlike the quadratic case, there's no closed-form solution for a cubic, so this follows
+    /// the approach used by WebKit/Servo's `UnitBezier`: seed a guess from the line through the
+    /// endpoints, refine it with Newton-Raphson, and fall back to bisection if Newton's method
+    /// doesn't converge (e.g. because the derivative is too close to zero).
+    pub fn solve_t_for_x(&self, x: f32) -> f32 {
+        let p0x = self.endpoints[0].x;
+        let p1x = self.control_points[0].x;
+        let p2x = self.control_points[1].x;
+        let p3x = self.endpoints[1].x;
+
+        let ax = -p0x + 3.0 * p1x - 3.0 * p2x + p3x;
+        let bx = 3.0 * p0x - 6.0 * p1x + 3.0 * p2x;
+        let cx = -3.0 * p0x + 3.0 * p1x;
+
+        let eval_x = |t: f32| ((ax * t + bx) * t + cx) * t + p0x;
+        let eval_dx = |t: f32| (3.0 * ax * t + 2.0 * bx) * t + cx;
+
+        const EPSILON: f32 = 1e-6;
+
+        let denom = p3x - p0x;
+        let mut t = if denom.approx_eq(&0.0) { 0.0 } else { (x - p0x) / denom };
+        t = t.max(0.0).min(1.0);
+
+        for _ in 0..8 {
+            let x_at_t = eval_x(t);
+            let error = x_at_t - x;
+            if error.abs() < EPSILON {
+                return t.max(0.0).min(1.0)
+            }
+            let derivative = eval_dx(t);
+            if derivative.abs() < EPSILON {
+                break
+            }
+            t -= error / derivative;
+            if t < 0.0 || t > 1.0 {
+                break
+            }
+        }
+
+        // Newton's method didn't converge (or stepped outside of `[0, 1]`); fall back to
+        // bisection, which is slower but always converges. The bracket is over `t`, not `x`, so
+        // it starts at the full `[0, 1]` parametric domain regardless of where `p0x`/`p3x` fall;
+        // which bound moves on each step is decided by comparing `x` against `eval_x` at the
+        // current bracket, not by assuming `x` increases from `p0x` to `p3x`.
+        let (mut t0, mut t1) = (0.0, 1.0);
+        let increasing = eval_x(t1) >= eval_x(t0);
+        let mut t = 0.5;
+        for _ in 0..32 {
+            let x_at_t = eval_x(t);
+            if (x_at_t - x).abs() < EPSILON {
+                return t.max(0.0).min(1.0)
+            }
+            if (x < x_at_t) == increasing {
+                t1 = t;
+            } else {
+                t0 = t;
+            }
+            t = (t0 + t1) * 0.5;
+        }
+
+        t.max(0.0).min(1.0)
+    }
+
+    #[inline]
+    pub fn solve_y_for_x(&self, x: f32) -> f32 {
+        self.sample(self.solve_t_for_x(x)).y
+    }
+
+    /// Recursively splits this cubic curve into quadratic curves that are each within
+    /// `error_tolerance` of the original cubic.
+    ///
+    /// The candidate quadratic's control point is the average of the two tangent-line estimates
+    /// of where a quadratic control point would need to sit to share this cubic's endpoints and
+    /// tangents; see https://fontforge.org/docs/techref/bezier.html for the derivation. The error
+    /// is the largest distance between the cubic and that candidate quadratic at equal `t`:
+    /// with `d = p3 - 3·p2 + 3·p1 - p0`, the two differ by `(d / 2)·t·(1 - t)·(1 - 2t)`, which
+    /// peaks at `sqrt(3) / 36 · |d|`. (The two curves always meet at `t = 0.5`, so comparing
+    /// midpoints alone would measure nothing.)
+    #[inline]
+    pub fn approximate_quadratics(&self, error_tolerance: f32) -> Vec<Curve> {
+        debug_assert!(error_tolerance > 0.0, "error_tolerance must be positive");
+        self.approximate_quadratics_at_depth(error_tolerance, 0)
+    }
+
+    // Recursion depth is capped so that a pathological (or zero/negative) `error_tolerance`
+    // can't blow the stack; in practice curves converge well within this many subdivisions.
+    const MAX_QUADRATIC_APPROXIMATION_DEPTH: u32 = 16;
+
+    fn approximate_quadratics_at_depth(&self, error_tolerance: f32, depth: u32) -> Vec<Curve> {
+        let (p0, p1, p2, p3) = (&self.endpoints[0],
+                                 &self.control_points[0],
+                                 &self.control_points[1],
+                                 &self.endpoints[1]);
+        let midpoint_control = Point2D::new((3.0 * p1.x - p0.x + 3.0 * p2.x - p3.x) / 4.0,
+                                             (3.0 * p1.y - p0.y + 3.0 * p2.y - p3.y) / 4.0);
+
+        let (dx, dy) = (p3.x - 3.0 * p2.x + 3.0 * p1.x - p0.x, p3.y - 3.0 * p2.y + 3.0 * p1.y - p0.y);
+        let error = (dx * dx + dy * dy).sqrt() * (3.0f32.sqrt() / 36.0);
+        if error <= error_tolerance || depth >= CubicCurve::MAX_QUADRATIC_APPROXIMATION_DEPTH {
+            return vec![Curve::new(p0, &midpoint_control, p3)]
+        }
+
+        let (first_half, second_half) = self.subdivide(0.5);
+        let mut quadratics = first_half.approximate_quadratics_at_depth(error_tolerance, depth + 1);
+        quadratics.extend(second_half.approximate_quadratics_at_depth(error_tolerance, depth + 1));
+        quadratics
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use euclid::Point2D;
+
+    use curve::{Curve, CubicCurve};
+
+    #[test]
+    fn cubic_sample_and_subdivide_agree_at_midpoint() {
+        let curve = CubicCurve::new(&Point2D::new(0.0, 0.0),
+                                     &Point2D::new(0.0, 1.0),
+                                     &Point2D::new(1.0, 1.0),
+                                     &Point2D::new(1.0, 0.0));
+        let midpoint = curve.sample(0.5);
+        let (first_half, second_half) = curve.subdivide(0.5);
+        assert!((first_half.endpoints[1].x - midpoint.x).abs() < 1e-6);
+        assert!((first_half.endpoints[1].y - midpoint.y).abs() < 1e-6);
+        assert_eq!(first_half.endpoints[1], second_half.endpoints[0]);
+    }
+
+    #[test]
+    fn inflection_points_finds_both_extrema_per_axis() {
+        // This S-curve has two x-axis tangent roots, at t ≈ 0.2113 and t ≈ 0.7887.
+        let curve = CubicCurve::new(&Point2D::new(0.0, 0.0),
+                                     &Point2D::new(1.0, 1.0),
+                                     &Point2D::new(-1.0, 2.0),
+                                     &Point2D::new(0.0, 3.0));
+        let (inflection_points_x, _) = curve.inflection_points();
+        assert_eq!(inflection_points_x.len(), 2);
+        assert!((inflection_points_x[0] - 0.2113).abs() < 1e-3);
+        assert!((inflection_points_x[1] - 0.7887).abs() < 1e-3);
+    }
+
+    #[test]
+    fn inflection_points_dedupes_a_repeated_root() {
+        // Derivative discriminant is exactly 0 here, so naively pushing both `(-b ± sqrt)/(2a)`
+        // roots would push the same `t ≈ 0.6667` twice.
+        let curve = CubicCurve::new(&Point2D::new(0.0, 0.0),
+                                     &Point2D::new(1.0, 0.0),
+                                     &Point2D::new(0.5, 0.0),
+                                     &Point2D::new(0.75, 0.0));
+        let (inflection_points_x, _) = curve.inflection_points();
+        assert_eq!(inflection_points_x.len(), 1);
+        assert!((inflection_points_x[0] - 0.6667).abs() < 1e-3);
+    }
+
+    #[test]
+    fn approximate_quadratics_respects_error_tolerance() {
+        let curve = CubicCurve::new(&Point2D::new(0.0, 0.0),
+                                     &Point2D::new(0.0, 1.0),
+                                     &Point2D::new(1.0, 1.0),
+                                     &Point2D::new(1.0, 0.0));
+        let quadratics = curve.approximate_quadratics(0.01);
+        assert!(!quadratics.is_empty());
+        assert_eq!(quadratics[0].endpoints[0], curve.endpoints[0]);
+        assert_eq!(quadratics[quadratics.len() - 1].endpoints[1], curve.endpoints[1]);
+        // Every sampled point of the cubic lies within the tolerance of some quadratic.
+        for i in 0..101 {
+            let point = curve.sample(i as f32 / 100.0);
+            let distance = quadratics.iter().flat_map(|quadratic| {
+                (0..101).map(move |j| {
+                    let sample = quadratic.sample(j as f32 / 100.0);
+                    ((sample.x - point.x).powi(2) + (sample.y - point.y).powi(2)).sqrt()
+                })
+            }).fold(::std::f32::INFINITY, f32::min);
+            assert!(distance < 0.01);
+        }
+    }
+
+    #[test]
+    fn approximate_quadratics_reports_zero_error_for_an_exact_quadratic() {
+        // A cubic that came from `Curve::elevate_to_cubic()` traces exactly the same path as
+        // the quadratic it was elevated from, so the error estimate should be (near) zero and a
+        // single quadratic should satisfy any positive tolerance, without needlessly subdividing.
+        let quadratic = Curve::new(&Point2D::new(0.0, 0.0),
+                                    &Point2D::new(0.5, 1.0),
+                                    &Point2D::new(1.0, 0.0));
+        let cubic = quadratic.elevate_to_cubic();
+        let quadratics = cubic.approximate_quadratics(0.01);
+        assert_eq!(quadratics.len(), 1);
+    }
+
+    #[test]
+    fn approximate_quadratics_does_not_overflow_on_unreachable_tolerance() {
+        let curve = CubicCurve::new(&Point2D::new(0.0, 0.0),
+                                     &Point2D::new(0.0, 1.0),
+                                     &Point2D::new(1.0, 1.0),
+                                     &Point2D::new(1.0, 0.0));
+        // With the recursion depth cap in place this terminates instead of overflowing the
+        // stack, even though `error <= error_tolerance` is satisfied at this tolerance for only
+        // a handful of branches before floating-point error bottoms out.
+        let quadratics = curve.approximate_quadratics(1e-30);
+        assert!(!quadratics.is_empty());
+        assert!(quadratics.len() <= 1 << CubicCurve::MAX_QUADRATIC_APPROXIMATION_DEPTH);
+    }
+
+    #[test]
+    fn solve_t_for_x_handles_non_monotonic_non_unit_domain_cubic() {
+        // A non-unit-domain cubic whose derivative vanishes, forcing the Newton step to bail
+        // out of `[0, 1]` and fall back to bisection.
+        let curve = CubicCurve::new(&Point2D::new(1.0, 0.0),
+                                     &Point2D::new(1.0, 0.0),
+                                     &Point2D::new(-0.788, 0.0),
+                                     &Point2D::new(-0.726, 0.0));
+        let t = curve.solve_t_for_x(-0.725);
+        assert!((t - 0.9609).abs() < 1e-3);
+    }
 }
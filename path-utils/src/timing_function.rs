@@ -0,0 +1,101 @@
+// pathfinder/path-utils/src/timing_function.rs
+//
+// Copyright © 2017 The Pathfinder Project Developers.
+//
+// Licensed under the Apache License, Version 2.0 <LICENSE-APACHE or
+// http://www.apache.org/licenses/LICENSE-2.0> or the MIT license
+// <LICENSE-MIT or http://opensource.org/licenses/MIT>, at your
+// option. This file may not be copied, modified, or distributed
+// except according to those terms.
+
+//! CSS-style `cubic-bezier()` timing functions, built on top of `CubicCurve`.
+
+use euclid::Point2D;
+
+use curve::CubicCurve;
+
+/// An easing curve of the kind produced by the CSS `cubic-bezier()` function: a unit Bézier
+/// curve running from `(0, 0)` to `(1, 1)` whose two interior control points are supplied by the
+/// caller.
+#[derive(Clone, Copy, PartialEq, Debug)]
+pub struct TimingFunction {
+    curve: CubicCurve,
+}
+
+impl TimingFunction {
+    #[inline]
+    pub fn new(control_point_0: &Point2D<f32>, control_point_1: &Point2D<f32>) -> TimingFunction {
+        let origin = Point2D::new(0.0, 0.0);
+        let destination = Point2D::new(1.0, 1.0);
+        TimingFunction {
+            curve: CubicCurve::new(&origin, control_point_0, control_point_1, &destination),
+        }
+    }
+
+    /// Given `progress` (the fraction of elapsed time, typically in `[0, 1]`), returns the
+    /// corresponding fraction of function advancement.
+    ///
+    /// This inverts the curve's x coordinate to find `t` and then samples the curve's y
+    /// coordinate at that `t`, matching how browsers evaluate `cubic-bezier()`.
+    #[inline]
+    pub fn sample(&self, progress: f32) -> f32 {
+        self.curve.solve_y_for_x(progress)
+    }
+
+    #[inline]
+    pub fn ease() -> TimingFunction {
+        TimingFunction::new(&Point2D::new(0.25, 0.1), &Point2D::new(0.25, 1.0))
+    }
+
+    #[inline]
+    pub fn ease_in() -> TimingFunction {
+        TimingFunction::new(&Point2D::new(0.42, 0.0), &Point2D::new(1.0, 1.0))
+    }
+
+    #[inline]
+    pub fn ease_out() -> TimingFunction {
+        TimingFunction::new(&Point2D::new(0.0, 0.0), &Point2D::new(0.58, 1.0))
+    }
+
+    #[inline]
+    pub fn ease_in_out() -> TimingFunction {
+        TimingFunction::new(&Point2D::new(0.42, 0.0), &Point2D::new(0.58, 1.0))
+    }
+
+    #[inline]
+    pub fn linear() -> TimingFunction {
+        TimingFunction::new(&Point2D::new(0.0, 0.0), &Point2D::new(1.0, 1.0))
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use timing_function::TimingFunction;
+
+    #[test]
+    fn linear_is_the_identity() {
+        let timing_function = TimingFunction::linear();
+        for i in 0..11 {
+            let progress = i as f32 / 10.0;
+            assert!((timing_function.sample(progress) - progress).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn presets_advance_from_zero_to_one() {
+        for timing_function in &[TimingFunction::ease(),
+                                  TimingFunction::ease_in(),
+                                  TimingFunction::ease_out(),
+                                  TimingFunction::ease_in_out()] {
+            assert!(timing_function.sample(0.0).abs() < 1e-3);
+            assert!((timing_function.sample(1.0) - 1.0).abs() < 1e-3);
+        }
+    }
+
+    #[test]
+    fn ease_in_starts_slower_than_ease_out() {
+        // `ease_in` lags behind the identity early on, while `ease_out` leads it.
+        assert!(TimingFunction::ease_in().sample(0.25) < 0.25);
+        assert!(TimingFunction::ease_out().sample(0.25) > 0.25);
+    }
+}